@@ -0,0 +1,280 @@
+use sqlx::Row;
+use tauri::State;
+
+use crate::config::{self, AppConfig};
+use crate::crypto::{self, VaultMeta, KEY_LEN};
+use crate::error::Error;
+use crate::models::{Account, Profile, Trade};
+use crate::state::AppState;
+
+fn require_key(state: &AppState) -> Result<[u8; KEY_LEN], Error> {
+    state.vault_key.lock().unwrap().ok_or(Error::Locked)
+}
+
+fn decrypt_string(key: &[u8; KEY_LEN], bytes: &[u8]) -> Result<String, Error> {
+    let plaintext = crypto::decrypt(key, bytes)?;
+    String::from_utf8(plaintext).map_err(|e| Error::Validation(e.to_string()))
+}
+
+/// Encrypts `profile`/`accounts` rows written before the vault existed.
+/// Only runs once, from the `unlock` first-run branch, so every row at this
+/// point is still plaintext from chunk0-1's original inserts. Runs inside
+/// the caller's transaction alongside the `vault_meta` insert so a crash
+/// partway through can't re-encrypt an already-encrypted row on the next
+/// `unlock` attempt.
+async fn encrypt_legacy_rows(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    key: &[u8; KEY_LEN],
+) -> Result<(), Error> {
+    let profiles = sqlx::query("SELECT id, name, email FROM profile")
+        .fetch_all(&mut **tx)
+        .await?;
+    for row in profiles {
+        let id: i64 = row.try_get("id")?;
+        let name: Vec<u8> = row.try_get("name")?;
+        let email: Option<Vec<u8>> = row.try_get("email")?;
+
+        let name_enc = crypto::encrypt(key, &name)?;
+        let email_enc = email.map(|e| crypto::encrypt(key, &e)).transpose()?;
+
+        sqlx::query("UPDATE profile SET name = ?, email = ? WHERE id = ?")
+            .bind(&name_enc)
+            .bind(&email_enc)
+            .bind(id)
+            .execute(&mut **tx)
+            .await?;
+    }
+
+    let accounts = sqlx::query("SELECT id, broker FROM accounts")
+        .fetch_all(&mut **tx)
+        .await?;
+    for row in accounts {
+        let id: i64 = row.try_get("id")?;
+        let broker: Vec<u8> = row.try_get("broker")?;
+        let broker_enc = crypto::encrypt(key, &broker)?;
+
+        sqlx::query("UPDATE accounts SET broker = ? WHERE id = ?")
+            .bind(&broker_enc)
+            .bind(id)
+            .execute(&mut **tx)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Derives (or, on first run, sets) the master key from `password` and holds
+/// it in memory for the rest of the session.
+#[tauri::command]
+pub async fn unlock(state: State<'_, AppState>, password: String) -> Result<(), Error> {
+    let meta =
+        sqlx::query_as::<_, VaultMeta>("SELECT salt, m_cost, t_cost, p_cost, verifier FROM vault_meta WHERE id = 1")
+            .fetch_optional(&state.pool)
+            .await?;
+
+    let key = match meta {
+        Some(meta) => {
+            let key = crypto::derive_key(
+                &password,
+                &meta.salt,
+                meta.m_cost as u32,
+                meta.t_cost as u32,
+                meta.p_cost as u32,
+            )?;
+            crypto::decrypt(&key, &meta.verifier)
+                .map_err(|_| Error::Validation("incorrect password".into()))?;
+            key
+        }
+        None => {
+            let salt = crypto::generate_salt();
+            let key = crypto::derive_key(
+                &password,
+                &salt,
+                crypto::DEFAULT_M_COST,
+                crypto::DEFAULT_T_COST,
+                crypto::DEFAULT_P_COST,
+            )?;
+            let verifier = crypto::encrypt(&key, crypto::VERIFIER_PLAINTEXT)?;
+
+            let mut tx = state.pool.begin().await?;
+
+            encrypt_legacy_rows(&mut tx, &key).await?;
+
+            sqlx::query(
+                "INSERT INTO vault_meta (id, salt, m_cost, t_cost, p_cost, verifier) VALUES (1, ?, ?, ?, ?, ?)",
+            )
+            .bind(salt.as_slice())
+            .bind(crypto::DEFAULT_M_COST)
+            .bind(crypto::DEFAULT_T_COST)
+            .bind(crypto::DEFAULT_P_COST)
+            .bind(&verifier)
+            .execute(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+
+            key
+        }
+    };
+
+    *state.vault_key.lock().unwrap() = Some(key);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_config(state: State<'_, AppState>) -> Result<AppConfig, Error> {
+    Ok(state.config.lock().unwrap().clone())
+}
+
+#[tauri::command]
+pub async fn save_config(state: State<'_, AppState>, config: AppConfig) -> Result<(), Error> {
+    config::save(&state.pool, &config).await?;
+    *state.config.lock().unwrap() = config;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn save_profile(state: State<'_, AppState>, profile: Profile) -> Result<i64, Error> {
+    let key = require_key(&state)?;
+    let name_enc = crypto::encrypt(&key, profile.name.as_bytes())?;
+    let email_enc = profile
+        .email
+        .as_deref()
+        .map(|e| crypto::encrypt(&key, e.as_bytes()))
+        .transpose()?;
+
+    let result = sqlx::query(
+        "INSERT INTO profile (name, email, experience, currency, timezone) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(&name_enc)
+    .bind(&email_enc)
+    .bind(&profile.experience)
+    .bind(&profile.currency)
+    .bind(&profile.timezone)
+    .execute(&state.pool)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+#[tauri::command]
+pub async fn get_profile(state: State<'_, AppState>) -> Result<Option<Profile>, Error> {
+    let key = require_key(&state)?;
+    let row = sqlx::query(
+        "SELECT id, name, email, experience, currency, timezone FROM profile ORDER BY id DESC LIMIT 1",
+    )
+    .fetch_optional(&state.pool)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+
+    let name_enc: Vec<u8> = row.try_get("name")?;
+    let email_enc: Option<Vec<u8>> = row.try_get("email")?;
+
+    Ok(Some(Profile {
+        id: row.try_get("id")?,
+        name: decrypt_string(&key, &name_enc)?,
+        email: email_enc.map(|e| decrypt_string(&key, &e)).transpose()?,
+        experience: row.try_get("experience")?,
+        currency: row.try_get("currency")?,
+        timezone: row.try_get("timezone")?,
+    }))
+}
+
+#[tauri::command]
+pub async fn save_account(state: State<'_, AppState>, account: Account) -> Result<i64, Error> {
+    let key = require_key(&state)?;
+    let broker_enc = crypto::encrypt(&key, account.broker.as_bytes())?;
+
+    let result = sqlx::query(
+        "INSERT INTO accounts (name, type, initial_balance, current_balance, broker, leverage, instruments)
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&account.name)
+    .bind(&account.account_type)
+    .bind(account.initial_balance)
+    .bind(account.current_balance)
+    .bind(&broker_enc)
+    .bind(&account.leverage)
+    .bind(&account.instruments)
+    .execute(&state.pool)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+#[tauri::command]
+pub async fn get_accounts(state: State<'_, AppState>) -> Result<Vec<Account>, Error> {
+    let key = require_key(&state)?;
+    let rows = sqlx::query(
+        "SELECT id, name, type, initial_balance, current_balance, broker, leverage, instruments FROM accounts ORDER BY id",
+    )
+    .fetch_all(&state.pool)
+    .await?;
+
+    rows.into_iter()
+        .map(|row| {
+            let broker_enc: Vec<u8> = row.try_get("broker")?;
+            Ok(Account {
+                id: row.try_get("id")?,
+                name: row.try_get("name")?,
+                account_type: row.try_get("type")?,
+                initial_balance: row.try_get("initial_balance")?,
+                current_balance: row.try_get("current_balance")?,
+                broker: decrypt_string(&key, &broker_enc)?,
+                leverage: row.try_get("leverage")?,
+                instruments: row.try_get("instruments")?,
+            })
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub async fn save_trade(state: State<'_, AppState>, trade: Trade) -> Result<i64, Error> {
+    require_key(&state)?;
+
+    let result = sqlx::query(
+        "INSERT INTO trades (
+            account_id, symbol, type, entry_price, exit_price, take_profit, stop_loss,
+            lot_size, volume, profit, commission, rr_ratio, strategy, session, duration, date
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(trade.account_id)
+    .bind(&trade.symbol)
+    .bind(&trade.trade_type)
+    .bind(trade.entry_price)
+    .bind(trade.exit_price)
+    .bind(trade.take_profit)
+    .bind(trade.stop_loss)
+    .bind(trade.lot_size)
+    .bind(trade.volume)
+    .bind(trade.profit)
+    .bind(trade.commission)
+    .bind(&trade.rr_ratio)
+    .bind(&trade.strategy)
+    .bind(&trade.session)
+    .bind(&trade.duration)
+    .bind(&trade.date)
+    .execute(&state.pool)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+#[tauri::command]
+pub async fn get_trades(state: State<'_, AppState>, account_id: i64) -> Result<Vec<Trade>, Error> {
+    require_key(&state)?;
+
+    let trades = sqlx::query_as::<_, Trade>(
+        "SELECT id, account_id, symbol, type, entry_price, exit_price, take_profit, stop_loss,
+                lot_size, volume, profit, commission, rr_ratio, strategy, session, duration, date
+         FROM trades WHERE account_id = ? ORDER BY date DESC",
+    )
+    .bind(account_id)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(trades)
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use crate::error::Error;
+
+const CONFIG_NAME: &str = "app";
+
+fn default_default_account() -> Option<i64> {
+    None
+}
+
+fn default_theme() -> String {
+    "system".to_string()
+}
+
+fn default_risk_percent() -> f64 {
+    1.0
+}
+
+fn default_session_timezone() -> String {
+    "UTC".to_string()
+}
+
+fn default_close_to_tray() -> bool {
+    false
+}
+
+/// Application preferences, persisted as a single JSON blob in the `config`
+/// table. New fields get a `#[serde(default = ...)]` function so old saved
+/// blobs keep loading after an update adds a preference.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    #[serde(default = "default_default_account")]
+    pub default_account: Option<i64>,
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    #[serde(default = "default_risk_percent")]
+    pub default_risk_percent: f64,
+    #[serde(default = "default_session_timezone")]
+    pub session_timezone: String,
+    /// When `true`, closing the main window hides it instead of exiting, so
+    /// the DB pool and single-instance lock stay alive in the background.
+    #[serde(default = "default_close_to_tray")]
+    pub close_to_tray: bool,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            default_account: default_default_account(),
+            theme: default_theme(),
+            default_risk_percent: default_risk_percent(),
+            session_timezone: default_session_timezone(),
+            close_to_tray: default_close_to_tray(),
+        }
+    }
+}
+
+/// Loads the persisted config, returning [`AppConfig::default`] when no row
+/// has been saved yet (first run).
+pub async fn load(pool: &SqlitePool) -> Result<AppConfig, Error> {
+    let row: Option<(String,)> = sqlx::query_as("SELECT data FROM config WHERE name = ?")
+        .bind(CONFIG_NAME)
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some((data,)) => Ok(serde_json::from_str(&data)?),
+        None => Ok(AppConfig::default()),
+    }
+}
+
+pub async fn save(pool: &SqlitePool, config: &AppConfig) -> Result<(), Error> {
+    let data = serde_json::to_string(config)?;
+    sqlx::query(
+        "INSERT INTO config (name, data) VALUES (?, ?)
+         ON CONFLICT (name) DO UPDATE SET data = excluded.data",
+    )
+    .bind(CONFIG_NAME)
+    .bind(data)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_blob_missing_close_to_tray_falls_back_to_default() {
+        let legacy_json = r#"{
+            "default_account": null,
+            "theme": "dark",
+            "default_risk_percent": 2.0,
+            "session_timezone": "UTC"
+        }"#;
+
+        let config: AppConfig = serde_json::from_str(legacy_json).unwrap();
+        assert_eq!(config.close_to_tray, default_close_to_tray());
+    }
+}
@@ -0,0 +1,104 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{ChaCha20Poly1305, KeyInit, Nonce};
+use rand::RngCore;
+
+use crate::error::Error;
+
+pub const SALT_LEN: usize = 16;
+pub const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// Known plaintext encrypted under the derived key and stashed in
+/// `vault_meta` so `unlock` can tell a wrong password from a right one.
+pub const VERIFIER_PLAINTEXT: &[u8] = b"fxtrades-vault-ok";
+
+/// OWASP-recommended Argon2id parameters (memory in KiB, iterations, parallelism).
+pub const DEFAULT_M_COST: u32 = 19456;
+pub const DEFAULT_T_COST: u32 = 2;
+pub const DEFAULT_P_COST: u32 = 1;
+
+pub fn generate_salt() -> [u8; SALT_LEN] {
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    salt
+}
+
+pub fn derive_key(
+    password: &str,
+    salt: &[u8],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+) -> Result<[u8; KEY_LEN], Error> {
+    let params = Params::new(m_cost, t_cost, p_cost, Some(KEY_LEN))
+        .map_err(|e| Error::Validation(e.to_string()))?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+    let mut key = [0u8; KEY_LEN];
+    argon2
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| Error::Validation(e.to_string()))?;
+    Ok(key)
+}
+
+/// Encrypts `plaintext`, returning `nonce || ciphertext`.
+pub fn encrypt(key: &[u8; KEY_LEN], plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+    let cipher = ChaCha20Poly1305::new(key.into());
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|_| Error::Validation("encryption failed".into()))?;
+
+    let mut out = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(&nonce_bytes);
+    out.extend(ciphertext);
+    Ok(out)
+}
+
+/// Decrypts a blob produced by [`encrypt`].
+pub fn decrypt(key: &[u8; KEY_LEN], data: &[u8]) -> Result<Vec<u8>, Error> {
+    if data.len() < NONCE_LEN {
+        return Err(Error::Validation("ciphertext too short".into()));
+    }
+    let (nonce_bytes, ciphertext) = data.split_at(NONCE_LEN);
+    let cipher = ChaCha20Poly1305::new(key.into());
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    cipher
+        .decrypt(nonce, ciphertext)
+        .map_err(|_| Error::Validation("decryption failed, wrong password?".into()))
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct VaultMeta {
+    pub salt: Vec<u8>,
+    pub m_cost: i64,
+    pub t_cost: i64,
+    pub p_cost: i64,
+    pub verifier: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_roundtrips() {
+        let key = [7u8; KEY_LEN];
+        let ciphertext = encrypt(&key, b"hello vault").unwrap();
+        let plaintext = decrypt(&key, &ciphertext).unwrap();
+        assert_eq!(plaintext, b"hello vault");
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_key() {
+        let key = [1u8; KEY_LEN];
+        let wrong_key = [2u8; KEY_LEN];
+        let ciphertext = encrypt(&key, b"secret broker").unwrap();
+        assert!(decrypt(&wrong_key, &ciphertext).is_err());
+    }
+}
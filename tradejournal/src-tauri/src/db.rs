@@ -0,0 +1,28 @@
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Manager};
+
+use crate::error::Error;
+
+const DB_FILE: &str = "trading_journal.db";
+
+/// Opens (creating if necessary) the journal's SQLite database in the app's
+/// data directory and brings it up to date via the versioned migrations in
+/// `migrations/`.
+pub async fn init_pool(app: &AppHandle) -> Result<SqlitePool, Error> {
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .expect("failed to resolve app data dir");
+    std::fs::create_dir_all(&data_dir).expect("failed to create app data dir");
+
+    let db_path = data_dir.join(DB_FILE);
+    let options = SqliteConnectOptions::new()
+        .filename(db_path)
+        .create_if_missing(true);
+
+    let pool = SqlitePoolOptions::new().connect_with(options).await?;
+    sqlx::migrate!("./migrations").run(&pool).await?;
+
+    Ok(pool)
+}
@@ -0,0 +1,39 @@
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use thiserror::Error as ThisError;
+
+/// Unified error type returned by every command so the frontend can branch
+/// on `kind` instead of parsing opaque strings.
+#[derive(Debug, ThisError)]
+pub enum Error {
+    #[error("database error: {0}")]
+    Db(#[from] sqlx::Error),
+    #[error("migration error: {0}")]
+    Migration(#[from] sqlx::migrate::MigrateError),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("validation error: {0}")]
+    Validation(String),
+    #[error("vault is locked")]
+    Locked,
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (kind, message) = match self {
+            Error::Db(e) => ("Db", e.to_string()),
+            Error::Migration(e) => ("Migration", e.to_string()),
+            Error::Serialization(e) => ("Serialization", e.to_string()),
+            Error::Validation(e) => ("Validation", e.clone()),
+            Error::Locked => ("Locked", self.to_string()),
+        };
+
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("kind", kind)?;
+        map.serialize_entry("message", &message)?;
+        map.end()
+    }
+}
@@ -1,159 +1,92 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use serde::{Deserialize, Serialize};
-use tauri::Manager;
-use tauri_plugin_sql::{Builder, Migration, MigrationKind};
-
-#[derive(Debug, Serialize, Deserialize)]
-struct Profile {
-    name: String,
-    email: Option<String>,
-    experience: String,
-    currency: String,
-    timezone: String,
-}
+mod commands;
+mod config;
+mod crypto;
+mod db;
+mod error;
+mod models;
+mod state;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Account {
-    id: Option<i64>,
-    name: String,
-    #[serde(rename = "type")]
-    account_type: String,
-    #[serde(rename = "initialBalance")]
-    initial_balance: f64,
-    #[serde(rename = "currentBalance")]
-    current_balance: f64,
-    broker: String,
-    leverage: String,
-    instruments: Option<String>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct Trade {
-    id: Option<i64>,
-    #[serde(rename = "accountId")]
-    account_id: i64,
-    symbol: String,
-    #[serde(rename = "type")]
-    trade_type: String,
-    #[serde(rename = "entryPrice")]
-    entry_price: f64,
-    #[serde(rename = "exitPrice")]
-    exit_price: Option<f64>,
-    #[serde(rename = "takeProfit")]
-    take_profit: f64,
-    #[serde(rename = "stopLoss")]
-    stop_loss: f64,
-    #[serde(rename = "lotSize")]
-    lot_size: f64,
-    volume: f64,
-    profit: f64,
-    commission: f64,
-    #[serde(rename = "rrRatio")]
-    rr_ratio: Option<String>,
-    strategy: Option<String>,
-    session: Option<String>,
-    duration: Option<String>,
-    date: String,
-}
+use std::sync::Mutex;
 
-#[tauri::command]
-async fn save_profile(profile: Profile) -> Result<String, String> {
-    // Using Tauri's SQL plugin execute directly
-    Ok(serde_json::to_string(&profile).map_err(|e| e.to_string())?)
-}
-
-#[tauri::command]
-async fn get_profile() -> Result<Option<Profile>, String> {
-    // Placeholder - will be handled by frontend SQL calls
-    Ok(None)
-}
-
-#[tauri::command]
-async fn save_account(account: Account) -> Result<String, String> {
-    Ok(serde_json::to_string(&account).map_err(|e| e.to_string())?)
-}
+use state::AppState;
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::Manager;
 
-#[tauri::command]
-async fn get_accounts() -> Result<Vec<Account>, String> {
-    Ok(vec![])
-}
+fn main() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.unminimize();
+                let _ = window.set_focus();
+            }
+        }))
+        .plugin(tauri_plugin_opener::init())
+        .setup(|app| {
+            let app_handle = app.handle().clone();
+            let pool = tauri::async_runtime::block_on(db::init_pool(&app_handle))
+                .expect("failed to initialize database pool");
+            let config = tauri::async_runtime::block_on(config::load(&pool))
+                .expect("failed to load app config");
+            app.manage(AppState {
+                pool,
+                vault_key: Mutex::new(None),
+                config: Mutex::new(config),
+            });
 
-#[tauri::command]
-async fn save_trade(trade: Trade) -> Result<String, String> {
-    Ok(serde_json::to_string(&trade).map_err(|e| e.to_string())?)
-}
+            if let Some(window) = app.get_webview_window("main") {
+                let window_handle = window.clone();
+                window.on_window_event(move |event| {
+                    if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                        let state = window_handle.state::<AppState>();
+                        let close_to_tray = state.config.lock().unwrap().close_to_tray;
+                        if close_to_tray {
+                            api.prevent_default();
+                            let _ = window_handle.hide();
+                        }
+                    }
+                });
+            }
 
-#[tauri::command]
-async fn get_trades(account_id: i64) -> Result<Vec<Trade>, String> {
-    Ok(vec![])
-}
+            // Gives hide-to-tray an actual quit path: without this there'd be
+            // no way back from a hidden window but relaunching the binary.
+            let show_item = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+            let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+            let tray_menu = Menu::with_items(app, &[&show_item, &quit_item])?;
 
-fn main() {
-    let migrations = vec![Migration {
-        version: 1,
-        description: "create initial tables",
-        sql: "
-                CREATE TABLE IF NOT EXISTS profile (
-                    id INTEGER PRIMARY KEY,
-                    name TEXT NOT NULL,
-                    email TEXT,
-                    experience TEXT NOT NULL,
-                    currency TEXT NOT NULL,
-                    timezone TEXT NOT NULL
-                );
-                
-                CREATE TABLE IF NOT EXISTS accounts (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    name TEXT NOT NULL,
-                    type TEXT NOT NULL,
-                    initial_balance REAL NOT NULL,
-                    current_balance REAL NOT NULL,
-                    broker TEXT NOT NULL,
-                    leverage TEXT NOT NULL,
-                    instruments TEXT
-                );
-                
-                CREATE TABLE IF NOT EXISTS trades (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    account_id INTEGER NOT NULL,
-                    symbol TEXT NOT NULL,
-                    type TEXT NOT NULL,
-                    entry_price REAL NOT NULL,
-                    exit_price REAL,
-                    take_profit REAL NOT NULL,
-                    stop_loss REAL NOT NULL,
-                    lot_size REAL NOT NULL,
-                    volume REAL NOT NULL,
-                    profit REAL NOT NULL,
-                    commission REAL DEFAULT 0,
-                    rr_ratio TEXT,
-                    strategy TEXT,
-                    session TEXT,
-                    duration TEXT,
-                    date TEXT NOT NULL,
-                    FOREIGN KEY (account_id) REFERENCES accounts (id)
-                );
-            ",
-        kind: MigrationKind::Up,
-    }];
+            TrayIconBuilder::new()
+                .icon(app.default_window_icon().cloned().unwrap())
+                .menu(&tray_menu)
+                .show_menu_on_left_click(true)
+                .on_menu_event(|app, event| match event.id().as_ref() {
+                    "show" => {
+                        if let Some(window) = app.get_webview_window("main") {
+                            let _ = window.show();
+                            let _ = window.unminimize();
+                            let _ = window.set_focus();
+                        }
+                    }
+                    "quit" => app.exit(0),
+                    _ => {}
+                })
+                .build(app)?;
 
-    tauri::Builder::default()
-        .plugin(tauri_plugin_sql::Builder::new().build())
-        .plugin(
-            Builder::default()
-                .add_migrations("sqlite:trading_journal.db", migrations)
-                .build(),
-        )
-        .plugin(tauri_plugin_opener::init())
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
-            save_profile,
-            get_profile,
-            save_account,
-            get_accounts,
-            save_trade,
-            get_trades
+            commands::unlock,
+            commands::get_config,
+            commands::save_config,
+            commands::save_profile,
+            commands::get_profile,
+            commands::save_account,
+            commands::get_accounts,
+            commands::save_trade,
+            commands::get_trades
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Profile {
+    pub id: Option<i64>,
+    pub name: String,
+    pub email: Option<String>,
+    pub experience: String,
+    pub currency: String,
+    pub timezone: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Account {
+    pub id: Option<i64>,
+    pub name: String,
+    #[serde(rename = "type")]
+    #[sqlx(rename = "type")]
+    pub account_type: String,
+    #[serde(rename = "initialBalance")]
+    #[sqlx(rename = "initial_balance")]
+    pub initial_balance: f64,
+    #[serde(rename = "currentBalance")]
+    #[sqlx(rename = "current_balance")]
+    pub current_balance: f64,
+    pub broker: String,
+    pub leverage: String,
+    pub instruments: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Trade {
+    pub id: Option<i64>,
+    #[serde(rename = "accountId")]
+    #[sqlx(rename = "account_id")]
+    pub account_id: i64,
+    pub symbol: String,
+    #[serde(rename = "type")]
+    #[sqlx(rename = "type")]
+    pub trade_type: String,
+    #[serde(rename = "entryPrice")]
+    #[sqlx(rename = "entry_price")]
+    pub entry_price: f64,
+    #[serde(rename = "exitPrice")]
+    #[sqlx(rename = "exit_price")]
+    pub exit_price: Option<f64>,
+    #[serde(rename = "takeProfit")]
+    #[sqlx(rename = "take_profit")]
+    pub take_profit: f64,
+    #[serde(rename = "stopLoss")]
+    #[sqlx(rename = "stop_loss")]
+    pub stop_loss: f64,
+    #[serde(rename = "lotSize")]
+    #[sqlx(rename = "lot_size")]
+    pub lot_size: f64,
+    pub volume: f64,
+    pub profit: f64,
+    pub commission: f64,
+    #[serde(rename = "rrRatio")]
+    #[sqlx(rename = "rr_ratio")]
+    pub rr_ratio: Option<String>,
+    pub strategy: Option<String>,
+    pub session: Option<String>,
+    pub duration: Option<String>,
+    pub date: String,
+}
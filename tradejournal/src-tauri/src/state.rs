@@ -0,0 +1,16 @@
+use std::sync::Mutex;
+
+use sqlx::SqlitePool;
+
+use crate::config::AppConfig;
+use crate::crypto::KEY_LEN;
+
+/// Shared backend state registered with `app.manage(...)` in `main`.
+pub struct AppState {
+    pub pool: SqlitePool,
+    /// Derived master key for the current session, set by `unlock` and held
+    /// only in memory; `None` means the vault is locked.
+    pub vault_key: Mutex<Option<[u8; KEY_LEN]>>,
+    /// Preferences loaded at startup and kept in sync with `save_config`.
+    pub config: Mutex<AppConfig>,
+}